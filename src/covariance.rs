@@ -0,0 +1,125 @@
+use crate::{Odometry, Velocity};
+use nalgebra::Matrix3;
+use std::time::Duration;
+
+/// 运动噪声系数
+///
+/// 反映底盘单步运动噪声随位移、转角增长的速率，打滑越严重的底盘应给出越大的系数
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MotionNoise {
+    /// 前向位移噪声系数 α
+    pub alpha: f32,
+    /// 侧向位移噪声系数 γ，非全向底盘侧向位移恒为 0，该项不起作用
+    pub gamma: f32,
+    /// 转角噪声系数 β
+    pub beta: f32,
+}
+
+/// 带协方差的里程计
+///
+/// 在 [`Odometry`] 基础上维护位姿 `(x, y, theta)` 的 3x3 协方差矩阵，
+/// 每走一步都按 EKF 预测步公式 `Σ ← G·Σ·Gᵀ + V·M·Vᵀ` 传播协方差，
+/// 可直接作为自定位算法的预测步使用
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OdometryWithCov {
+    /// 当前里程
+    pub odometry: Odometry,
+    /// 位姿 `(x, y, theta)` 的协方差矩阵
+    pub cov: Matrix3<f32>,
+}
+
+impl OdometryWithCov {
+    /// 协方差为零的初始状态，用于初始化
+    pub const ZERO: Self = Self {
+        odometry: Odometry::ZERO,
+        cov: Matrix3::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+    };
+
+    /// 用一步瞬时速度 `velocity` 推进里程并传播协方差
+    ///
+    /// `dt` 是本步时长，`noise` 是该底盘的运动噪声系数；
+    /// 非全向底盘 `velocity.vy` 恒为 0，侧向噪声项自然为 0
+    pub fn propagate(&mut self, velocity: Velocity, dt: Duration, noise: MotionNoise) {
+        let dt_secs = dt.as_secs_f32();
+        let s = velocity.vx * dt_secs;
+        let l = velocity.vy * dt_secs;
+        let theta = velocity.w * dt_secs;
+        let step = velocity * dt;
+
+        let phi = self.odometry.pose.rotation.angle();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let lx = step.pose.translation.vector[0];
+        let ly = step.pose.translation.vector[1];
+
+        // G：位姿合成对先验位姿 (x, y, phi) 的雅可比
+        #[rustfmt::skip]
+        let g = Matrix3::new(
+            1.0, 0.0, -lx * sin_phi - ly * cos_phi,
+            0.0, 1.0,  lx * cos_phi - ly * sin_phi,
+            0.0, 0.0,  1.0,
+        );
+
+        // V：位姿合成对控制量 (s, l, theta) 的雅可比，lx = s·A − l·B，ly = s·B + l·A
+        let (a, b, da, db) = arc_derivatives(theta);
+        let dlx_dtheta = s * da - l * db;
+        let dly_dtheta = s * db + l * da;
+        #[rustfmt::skip]
+        let v = Matrix3::new(
+            cos_phi * a - sin_phi * b, -cos_phi * b - sin_phi * a, cos_phi * dlx_dtheta - sin_phi * dly_dtheta,
+            sin_phi * a + cos_phi * b, -sin_phi * b + cos_phi * a, sin_phi * dlx_dtheta + cos_phi * dly_dtheta,
+            0.0,                        0.0,                       1.0,
+        );
+
+        let m = Matrix3::from_diagonal(&nalgebra::Vector3::new(
+            noise.alpha * s.abs(),
+            noise.gamma * l.abs(),
+            noise.beta * theta.abs(),
+        ));
+
+        self.cov = g * self.cov * g.transpose() + v * m * v.transpose();
+        self.odometry += step;
+    }
+}
+
+/// 弧长模型里 `sinc(theta) = sin(theta)/theta`、`verc(theta) = (1-cos(theta))/theta`
+/// 及二者对 `theta` 的导数，`theta → 0` 时取解析极限以避免除零
+fn arc_derivatives(theta: f32) -> (f32, f32, f32, f32) {
+    if theta.abs() < 1e-4 {
+        (1.0, 0.0, 0.0, 0.5)
+    } else {
+        let (sin, cos) = theta.sin_cos();
+        let sinc = sin / theta;
+        let verc = (1.0 - cos) / theta;
+        let dsinc = (theta * cos - sin) / (theta * theta);
+        let dverc = (theta * sin - (1.0 - cos)) / (theta * theta);
+        (sinc, verc, dsinc, dverc)
+    }
+}
+
+#[test]
+fn pure_lateral_step_grows_covariance() {
+    let mut odom = OdometryWithCov::ZERO;
+    let noise = MotionNoise {
+        alpha: 0.1,
+        gamma: 0.1,
+        beta: 0.1,
+    };
+    odom.propagate(
+        Velocity {
+            vx: 0.0,
+            vy: 1.0,
+            w: 0.0,
+        },
+        Duration::from_secs(1),
+        noise,
+    );
+
+    // 1m 的纯侧向位移不应再被当作零不确定度增长
+    assert!(
+        odom.cov.iter().any(|c| c.abs() > f32::EPSILON),
+        "lateral motion must grow covariance, got {:?}",
+        odom.cov
+    );
+    // y 方向的不确定度应随侧向位移增长
+    assert!(odom.cov[(1, 1)] > 0.0);
+}
@@ -0,0 +1,223 @@
+use crate::{ChassisModel, Isometry2, StatusPredictor, Velocity};
+use std::time::Duration;
+
+/// 动态窗口法（Dynamic Window Approach）状态预测器
+///
+/// 每个控制周期都在当前速度附近采样一组 `(v, w)` 候选指令（动态窗口），
+/// 对每个候选指令前向仿真 `horizon` 时长，按朝向目标、前进速度、避障三项
+/// 加权打分，选出得分最高的指令作为下一周期的状态
+pub struct DynamicWindow<M, F>
+where
+    M: ChassisModel<State = Velocity>,
+    F: Fn(Isometry2<f32>) -> f32,
+{
+    /// 控制周期，同时也是前向仿真的步长
+    pub period: Duration,
+    /// 前向仿真的总时长
+    pub horizon: Duration,
+    /// 线速度绝对上限 m/s
+    pub v_max: f32,
+    /// 角速度绝对上限 rad/s
+    pub w_max: f32,
+    /// 线加速度上限 m/s²，决定动态窗口的线速度范围
+    pub a_v: f32,
+    /// 角加速度上限 rad/s²，决定动态窗口的角速度范围
+    pub a_w: f32,
+    /// 线速度采样分辨率 m/s
+    pub v_resolution: f32,
+    /// 角速度采样分辨率 rad/s
+    pub w_resolution: f32,
+    /// 朝向目标得分的权重
+    pub heading_weight: f32,
+    /// 前进速度得分的权重
+    pub velocity_weight: f32,
+    /// 避障代价的权重
+    pub obstacle_weight: f32,
+
+    /// 底盘模型，用于将仿真出的 `Velocity` 候选积分为里程增量
+    pub model: M,
+    /// 避障代价函数：给定世界坐标系下的候选位姿，返回越靠近障碍物越大的代价
+    pub obstacle_cost: F,
+    /// 世界坐标系下的目标位姿
+    pub goal: Isometry2<f32>,
+
+    /// 上一周期选中的速度，作为本周期动态窗口的中心
+    pub current: Velocity,
+    /// 当前估计的机器人位姿，每选出一条指令后都会前推一个 `period`
+    pub pose: Isometry2<f32>,
+}
+
+impl<M, F> Clone for DynamicWindow<M, F>
+where
+    M: ChassisModel<State = Velocity> + Clone,
+    F: Fn(Isometry2<f32>) -> f32 + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            period: self.period,
+            horizon: self.horizon,
+            v_max: self.v_max,
+            w_max: self.w_max,
+            a_v: self.a_v,
+            a_w: self.a_w,
+            v_resolution: self.v_resolution,
+            w_resolution: self.w_resolution,
+            heading_weight: self.heading_weight,
+            velocity_weight: self.velocity_weight,
+            obstacle_weight: self.obstacle_weight,
+            model: self.model.clone(),
+            obstacle_cost: self.obstacle_cost.clone(),
+            goal: self.goal,
+            current: self.current,
+            pose: self.pose,
+        }
+    }
+}
+
+impl<M, F> DynamicWindow<M, F>
+where
+    M: ChassisModel<State = Velocity>,
+    F: Fn(Isometry2<f32>) -> f32,
+{
+    /// 前向仿真一个候选指令 `horizon` 时长，按朝向、前进、避障加权打分
+    fn score(&self, candidate: Velocity) -> f32 {
+        let actual = self.model.drive(&candidate);
+        let step = (actual * self.period).pose;
+        let steps = (self.horizon.as_secs_f32() / self.period.as_secs_f32())
+            .round()
+            .max(1.0) as usize;
+
+        let mut pose = self.pose;
+        // 沿整条轨迹取最靠近障碍物（代价最大）的一点，而非最安全的一点，
+        // 否则轨迹中段擦碰障碍物、末段又远离时会被误判为安全
+        let mut worst_obstacle_cost = f32::NEG_INFINITY;
+        for _ in 0..steps {
+            pose *= step;
+            worst_obstacle_cost = worst_obstacle_cost.max((self.obstacle_cost)(pose));
+        }
+
+        let to_goal = pose.inverse() * self.goal;
+        let heading = to_goal.translation.vector[1].atan2(to_goal.translation.vector[0]);
+        let heading_score = std::f32::consts::PI - heading.abs();
+
+        self.heading_weight * heading_score
+            + self.velocity_weight * actual.vx
+            + self.obstacle_weight * worst_obstacle_cost
+    }
+}
+
+impl<M, F> StatusPredictor for DynamicWindow<M, F>
+where
+    M: ChassisModel<State = Velocity> + Clone,
+    F: Fn(Isometry2<f32>) -> f32 + Clone,
+{
+    type Model = M;
+
+    /// 采样动态窗口内的 `(v, w)` 候选，返回得分最高的一个
+    fn predict(&mut self) -> Option<Velocity> {
+        let dt = self.period.as_secs_f32();
+        let v_lo = (self.current.vx - self.a_v * dt).max(-self.v_max);
+        let v_hi = (self.current.vx + self.a_v * dt).min(self.v_max);
+        let w_lo = (self.current.w - self.a_w * dt).max(-self.w_max);
+        let w_hi = (self.current.w + self.a_w * dt).min(self.w_max);
+        if v_lo > v_hi || w_lo > w_hi || self.v_resolution <= 0.0 || self.w_resolution <= 0.0 {
+            return None;
+        }
+
+        let mut best: Option<(Velocity, f32)> = None;
+        let mut v = v_lo;
+        while v <= v_hi + f32::EPSILON {
+            let mut w = w_lo;
+            while w <= w_hi + f32::EPSILON {
+                let candidate = Velocity { vx: v, vy: 0.0, w };
+                let score = self.score(candidate);
+                if best.is_none_or(|(_, best_score)| score > best_score) {
+                    best = Some((candidate, score));
+                }
+                w += self.w_resolution;
+            }
+            v += self.v_resolution;
+        }
+
+        best.map(|(candidate, _)| {
+            self.current = candidate;
+            let actual = self.model.drive(&candidate);
+            self.pose *= (actual * self.period).pose;
+            candidate
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct IdentityModel;
+
+    impl ChassisModel for IdentityModel {
+        type State = Velocity;
+        type Measure = Velocity;
+
+        fn drive(&self, s: &Velocity) -> Velocity {
+            *s
+        }
+
+        fn measure(&self, m: &Velocity) -> Velocity {
+            *m
+        }
+    }
+
+    fn window<F: Fn(Isometry2<f32>) -> f32 + Clone>(
+        obstacle_cost: F,
+    ) -> DynamicWindow<IdentityModel, F> {
+        DynamicWindow {
+            period: Duration::from_millis(100),
+            horizon: Duration::from_millis(500),
+            v_max: 1.0,
+            w_max: 1.0,
+            a_v: 10.0,
+            a_w: 10.0,
+            v_resolution: 1.0,
+            w_resolution: 1.0,
+            heading_weight: 0.0,
+            velocity_weight: 0.0,
+            obstacle_weight: -1.0,
+            model: IdentityModel,
+            obstacle_cost,
+            goal: crate::isometry(10.0, 0.0, 1.0, 0.0),
+            current: Velocity {
+                vx: 0.0,
+                vy: 0.0,
+                w: 0.0,
+            },
+            pose: crate::isometry(0.0, 0.0, 1.0, 0.0),
+        }
+    }
+
+    /// 障碍物位于轨迹中段：直行指令擦碰障碍物，应当比原地不动评分更低
+    #[test]
+    fn worst_case_obstacle_on_trajectory_is_penalized() {
+        // 障碍物代价在 x = 0.25m 处最高，随距离线性衰减到 0
+        let dw = window(|pose: Isometry2<f32>| {
+            let x = pose.translation.vector[0];
+            (1.0 - (x - 0.25).abs() * 4.0).max(0.0)
+        });
+
+        let score_straight = dw.score(Velocity {
+            vx: 1.0,
+            vy: 0.0,
+            w: 0.0,
+        });
+        let score_stationary = dw.score(Velocity {
+            vx: 0.0,
+            vy: 0.0,
+            w: 0.0,
+        });
+
+        assert!(
+            score_straight < score_stationary,
+            "straight path through the obstacle should score lower: {score_straight} >= {score_stationary}"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Isometry2, Odometry, Velocity};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// 位姿外推器
+///
+/// 维护一个按时间排序的位姿队列（通常来自点云匹配等外部修正）和一个里程队列，
+/// 在两次修正之间以恒定速度模型将最新位姿外推到任意时间点，
+/// 从而让底盘能以远高于修正频率的速率持续输出位姿估计
+#[derive(Clone, Debug)]
+pub struct PoseExtrapolator {
+    /// 参与速度估计的位姿队列最大时间跨度
+    pose_queue_duration: Duration,
+    poses: VecDeque<(Instant, Isometry2<f32>)>,
+    odometries: VecDeque<(Instant, Odometry)>,
+}
+
+impl PoseExtrapolator {
+    /// 创建外推器，`pose_queue_duration` 是参与速度估计的位姿队列最大时间跨度
+    pub fn new(pose_queue_duration: Duration) -> Self {
+        Self {
+            pose_queue_duration,
+            poses: VecDeque::new(),
+            odometries: VecDeque::new(),
+        }
+    }
+
+    /// 添加一个外部修正位姿，例如来自点云匹配的结果
+    pub fn add_pose(&mut self, time: Instant, pose: Isometry2<f32>) {
+        self.poses.push_back((time, pose));
+        while let Some(&(oldest, _)) = self.poses.front() {
+            if time.duration_since(oldest) > self.pose_queue_duration && self.poses.len() > 1 {
+                self.poses.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// 添加一条里程计样本
+    pub fn add_odometry(&mut self, time: Instant, odometry: Odometry) {
+        self.odometries.push_back((time, odometry));
+        while let Some(&(oldest, _)) = self.odometries.front() {
+            if time.duration_since(oldest) > self.pose_queue_duration && self.odometries.len() > 1 {
+                self.odometries.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// 外推 `time` 时刻的位姿
+    ///
+    /// 取队列中最新的位姿作为基准，用估计的瞬时速度做恒速外推；
+    /// 若队列为空则返回原点位姿
+    pub fn extrapolate_pose(&self, time: Instant) -> Isometry2<f32> {
+        let Some(&(latest_time, latest_pose)) = self.poses.back() else {
+            return crate::isometry(0.0, 0.0, 1.0, 0.0);
+        };
+        let dt = time
+            .checked_duration_since(latest_time)
+            .unwrap_or(Duration::ZERO);
+        match self.estimate_velocity() {
+            Some(v) => latest_pose * (v * dt).pose,
+            None => latest_pose,
+        }
+    }
+
+    /// 估计当前瞬时速度
+    ///
+    /// 优先使用位姿队列首尾两个位姿做有限差分；位姿样本不足两个时，
+    /// 退化为使用里程队列最新两条样本估计
+    fn estimate_velocity(&self) -> Option<Velocity> {
+        if self.poses.len() >= 2 {
+            let (t0, p0) = *self.poses.front().unwrap();
+            let (t1, p1) = *self.poses.back().unwrap();
+            velocity_between(t0, p0, t1, p1)
+        } else if self.odometries.len() >= 2 {
+            let n = self.odometries.len();
+            let (t0, o0) = self.odometries[n - 2];
+            let (t1, o1) = self.odometries[n - 1];
+            velocity_between(t0, o0.pose, t1, o1.pose)
+        } else {
+            None
+        }
+    }
+}
+
+/// 由两个带时间戳的位姿做有限差分，得到 `t0` 到 `t1` 区间内的平均机体速度
+fn velocity_between(
+    t0: Instant,
+    p0: Isometry2<f32>,
+    t1: Instant,
+    p1: Isometry2<f32>,
+) -> Option<Velocity> {
+    let dt = t1.checked_duration_since(t0)?.as_secs_f32();
+    if dt <= f32::EPSILON {
+        return None;
+    }
+    let delta = p0.inverse() * p1;
+    Some(Velocity {
+        vx: delta.translation.vector[0] / dt,
+        vy: delta.translation.vector[1] / dt,
+        w: delta.rotation.angle() / dt,
+    })
+}
+
+#[test]
+fn constant_velocity_extrapolation() {
+    let t0 = Instant::now();
+    let mut extrapolator = PoseExtrapolator::new(Duration::from_secs(10));
+    extrapolator.add_pose(t0, crate::isometry(0.0, 0.0, 1.0, 0.0));
+    extrapolator.add_pose(
+        t0 + Duration::from_secs(1),
+        crate::isometry(1.0, 0.0, 1.0, 0.0),
+    );
+
+    let predicted = extrapolator.extrapolate_pose(t0 + Duration::from_secs(2));
+    assert!((predicted.translation.vector[0] - 2.0).abs() < 1e-4);
+}
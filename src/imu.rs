@@ -0,0 +1,132 @@
+use crate::{ChassisModel, MotionNoise, Velocity};
+use std::time::Duration;
+
+/// 一次编码器 + 陀螺仪联合测量
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EncoderImuMeasure {
+    /// 左轮位移增量，单位 m
+    pub left_dist: f32,
+    /// 右轮位移增量，单位 m
+    pub right_dist: f32,
+    /// 陀螺仪角速度读数，单位 rad/s
+    pub gyro_w: f32,
+    /// 本次测量经过的时间，用于将轮式位移增量换算为速度
+    pub dt: Duration,
+}
+
+/// 融合陀螺仪航向与轮式编码器位移的差动底盘模型
+///
+/// 平移分量仍由左右轮位移的均值给出，但转动分量按 `gyro_trust` 在编码器解算值
+/// 与陀螺仪读数之间加权融合；陀螺仪不受轮子打滑影响，因此短时间窗口内远比
+/// 纯编码器差动里程抗漂移
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ImuFusedDiffDrive {
+    /// 两驱动轮轮距的一半，单位 m
+    pub body_radius: f32,
+    /// 陀螺仪读数在融合角速度中的权重，范围 `[0, 1]`，`1` 表示完全信任陀螺仪
+    pub gyro_trust: f32,
+    /// 纯编码器差动模型的运动噪声系数，作为 [`ChassisModel::motion_noise`] 的基准
+    pub base_noise: MotionNoise,
+}
+
+impl ChassisModel for ImuFusedDiffDrive {
+    type State = Velocity;
+    type Measure = EncoderImuMeasure;
+
+    fn drive(&self, s: &Velocity) -> Velocity {
+        *s
+    }
+
+    fn measure(&self, m: &EncoderImuMeasure) -> Velocity {
+        let EncoderImuMeasure {
+            left_dist,
+            right_dist,
+            gyro_w,
+            dt,
+        } = *m;
+        let dt = dt.as_secs_f32();
+        if dt <= f32::EPSILON {
+            // dt 退化为 0（重复时间戳或首帧），轮式位移无法换算成速度；
+            // 退化为只信任陀螺仪角速度、不产生平移，避免除零得到 inf/NaN
+            return Velocity {
+                vx: 0.0,
+                vy: 0.0,
+                w: gyro_w,
+            };
+        }
+        // 轮式位移增量（m）先换算成速度（m/s）、角速度（rad/s），再与陀螺仪读数融合
+        let w_encoder = (right_dist - left_dist) / (2.0 * self.body_radius * dt);
+        Velocity {
+            vx: (left_dist + right_dist) / (2.0 * dt),
+            vy: 0.0,
+            w: self.gyro_trust * gyro_w + (1.0 - self.gyro_trust) * w_encoder,
+        }
+    }
+
+    /// 陀螺仪提供的航向不受轮子打滑影响，融合权重越高，角度噪声系数越小
+    fn motion_noise(&self) -> MotionNoise {
+        MotionNoise {
+            alpha: self.base_noise.alpha,
+            gamma: self.base_noise.gamma,
+            beta: self.base_noise.beta * (1.0 - self.gyro_trust),
+        }
+    }
+}
+
+#[test]
+fn measure_converts_displacement_to_rate_and_blends_heading() {
+    let model = ImuFusedDiffDrive {
+        body_radius: 0.5,
+        gyro_trust: 0.0,
+        base_noise: MotionNoise {
+            alpha: 0.1,
+            gamma: 0.1,
+            beta: 0.1,
+        },
+    };
+    // 左右轮各行驶 1m，耗时 2s：vx = 0.5 m/s
+    let v = model.measure(&EncoderImuMeasure {
+        left_dist: 1.0,
+        right_dist: 1.0,
+        gyro_w: 1.0,
+        dt: Duration::from_secs(2),
+    });
+    assert!((v.vx - 0.5).abs() < f32::EPSILON);
+    // gyro_trust = 0，完全采用编码器角速度；左右轮位移相同，角速度应为 0
+    assert!(v.w.abs() < f32::EPSILON);
+
+    let model = ImuFusedDiffDrive {
+        gyro_trust: 1.0,
+        ..model
+    };
+    let v = model.measure(&EncoderImuMeasure {
+        left_dist: 1.0,
+        right_dist: 1.0,
+        gyro_w: 1.0,
+        dt: Duration::from_secs(2),
+    });
+    // gyro_trust = 1，完全采用陀螺仪读数，忽略编码器角速度
+    assert!((v.w - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn measure_guards_against_zero_dt() {
+    let model = ImuFusedDiffDrive {
+        body_radius: 0.5,
+        gyro_trust: 0.0,
+        base_noise: MotionNoise {
+            alpha: 0.1,
+            gamma: 0.1,
+            beta: 0.1,
+        },
+    };
+    let v = model.measure(&EncoderImuMeasure {
+        left_dist: 1.0,
+        right_dist: 1.0,
+        gyro_w: 1.0,
+        dt: Duration::from_secs(0),
+    });
+    assert!(v.vx.is_finite() && v.w.is_finite());
+    assert_eq!(v.vx, 0.0);
+    assert_eq!(v.w, 1.0);
+}
@@ -1,11 +1,21 @@
+mod covariance;
+mod dwa;
+mod extrapolate;
+mod imu;
 mod odometry;
 mod predict;
+mod wheels;
 
 use std::time::Duration;
 
+pub use covariance::{MotionNoise, OdometryWithCov};
+pub use dwa::DynamicWindow;
+pub use extrapolate::PoseExtrapolator;
+pub use imu::{EncoderImuMeasure, ImuFusedDiffDrive};
 pub use nalgebra::Isometry2;
 pub use odometry::Odometry;
 pub use predict::{StatusPredictor, TrajectoryPredictor};
+pub use wheels::{DiffDrive, TriOmni, WheelKinematics};
 
 /// 底盘模型
 pub trait ChassisModel {
@@ -20,30 +30,56 @@ pub trait ChassisModel {
 
     /// 根据一个测量估计底盘转动中心相对地面的速度
     fn measure(&self, m: &Self::Measure) -> Velocity;
+
+    /// 该底盘的运动噪声系数，用于 [`OdometryWithCov`] 的协方差传播
+    ///
+    /// 默认无噪声；容易打滑的底盘应覆盖此方法给出更大的系数
+    fn motion_noise(&self) -> MotionNoise {
+        MotionNoise {
+            alpha: 0.0,
+            gamma: 0.0,
+            beta: 0.0,
+        }
+    }
 }
 
 /// 刚体速度模型
+///
+/// 机器人坐标系下 `x` 朝前、`y` 朝左，因此二轮差动等非全向底盘只需保持 `vy = 0`
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Velocity {
-    /// 旋转中心相对地面线速度 m/s
-    pub v: f32,
+    /// 机器人坐标系前向线速度 m/s
+    pub vx: f32,
+    /// 机器人坐标系侧向（左侧为正）线速度 m/s，全向底盘专用，非全向底盘取 0
+    pub vy: f32,
     /// 旋转中心相对地面角速度 rad/s
     pub w: f32,
 }
 
 impl Velocity {
     pub fn to_odometry(&self) -> Odometry {
-        let Velocity { v: s, w: theta } = *self;
+        let Velocity {
+            vx: sx,
+            vy: sy,
+            w: theta,
+        } = *self;
         let a = theta.abs();
         let (sin, cos) = theta.sin_cos();
         Odometry {
-            s: s.abs(),
+            s: sx.abs(),
+            l: sy.abs(),
             a,
             pose: if a < f32::EPSILON {
-                isometry(s, 0.0, cos, sin)
+                isometry(sx, sy, cos, sin)
             } else {
-                let radius = s / theta;
-                isometry(radius * sin, radius * (1.0 - cos), cos, sin)
+                let rx = sx / theta;
+                let ry = sy / theta;
+                isometry(
+                    rx * sin - ry * (1.0 - cos),
+                    rx * (1.0 - cos) + ry * sin,
+                    cos,
+                    sin,
+                )
             },
         }
     }
@@ -54,7 +90,8 @@ impl std::ops::Mul<f32> for Velocity {
 
     #[inline]
     fn mul(mut self, rhs: f32) -> Self::Output {
-        self.v *= rhs;
+        self.vx *= rhs;
+        self.vy *= rhs;
         self.w *= rhs;
         self.to_odometry()
     }
@@ -1,19 +1,24 @@
 /// 里程计模型，表示当前机器人位姿
 ///
 /// 采用两轮差动模型，轨迹为圆弧，给定单步弧长、转角 `(s，theta)`，累计得到当前位置和姿态 `pose`
+/// 全向底盘还会给出侧向位移，累计到 `l`
 ///
 /// ## NOTICE
 ///
 /// 里程计初始化，可设为默认原点 `Odometry::ZERO`
-/// 里程计增量，借用 `Velocity` 结构体，给定位移 `s` 和角度 `theta` ，得到 `delta_odometry = Odometry::from(Velocity{v: s, w: theta})`
+/// 里程计增量，借用 `Velocity` 结构体，给定位移 `(vx，vy)` 和角度 `theta` ，得到 `delta_odometry = Velocity{vx, vy, w: theta}.to_odometry()`
 /// 累加增量，可直接用 `+=` 运算，即 `Odometry += delta_Odometry::from(Velocity)`
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Odometry {
-    /// 机器人行驶总里程，单位 m
+    /// 机器人行驶总里程（前向分量），单位 m
     ///
     /// 这个量是单调增的
     pub s: f32,
+    /// 机器人行驶总侧移（全向底盘侧向分量），单位 m
+    ///
+    /// 这个量是单调增的，非全向底盘恒为 0
+    pub l: f32,
     /// 机器人行驶总转角，单位 rad
     ///
     /// 这个量是单调增的
@@ -26,6 +31,7 @@ impl Odometry {
     /// 零里程，用于初始化
     pub const ZERO: Self = Self {
         s: 0.0,
+        l: 0.0,
         a: 0.0,
         pose: crate::isometry(0.0, 0.0, 1.0, 0.0),
     };
@@ -36,6 +42,7 @@ impl std::ops::AddAssign for Odometry {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.s += rhs.s;
+        self.l += rhs.l;
         self.a += rhs.a;
         // 位姿的叠加在 SE(2) 中用乘法表示
         self.pose *= rhs.pose;
@@ -72,16 +79,13 @@ impl std::fmt::Display for Odometry {
 #[test]
 fn odometry_test() {
     use crate::Velocity;
-    use std::{
-        f32::{consts::PI, EPSILON},
-        time::Duration,
-    };
+    use std::{f32::consts::PI, time::Duration};
 
     #[inline]
     fn pose_equal(a: crate::Isometry2<f32>, b: crate::Isometry2<f32>) -> bool {
-        (a.translation.vector[0] - b.translation.vector[0]).abs() <= EPSILON
-            || (a.translation.vector[1] - b.translation.vector[1]).abs() <= EPSILON
-            || (a.rotation.angle() - b.rotation.angle()).abs() <= EPSILON
+        (a.translation.vector[0] - b.translation.vector[0]).abs() <= f32::EPSILON
+            || (a.translation.vector[1] - b.translation.vector[1]).abs() <= f32::EPSILON
+            || (a.rotation.angle() - b.rotation.angle()).abs() <= f32::EPSILON
     }
 
     //测试里程计原点及输出是否正确
@@ -95,7 +99,8 @@ fn odometry_test() {
     let circumference = 2.0 * PI * radius;
     let step_num = 10.0;
     let delta_vel = Velocity {
-        v: (circumference / step_num),
+        vx: (circumference / step_num),
+        vy: 0.0,
         w: (PI * 2.0 / step_num),
     };
     let dd = delta_vel * Duration::from_secs(1);
@@ -110,3 +115,21 @@ fn odometry_test() {
         Odometry::ZERO.pose
     );
 }
+
+/// 全向底盘纯侧移（`w = 0`）应直接按 `vy·t` 平移，并累计到 `l`
+#[test]
+fn holonomic_lateral_step() {
+    use crate::Velocity;
+    use std::time::Duration;
+
+    let increment = Velocity {
+        vx: 0.0,
+        vy: 2.0,
+        w: 0.0,
+    } * Duration::from_secs(1);
+
+    assert!((increment.pose.translation.vector[0]).abs() < f32::EPSILON);
+    assert!((increment.pose.translation.vector[1] - 2.0).abs() < f32::EPSILON);
+    assert!((increment.l - 2.0).abs() < f32::EPSILON);
+    assert!((increment.s).abs() < f32::EPSILON);
+}
@@ -0,0 +1,102 @@
+use crate::Velocity;
+
+/// 轮速运动学
+///
+/// 在底盘 [`Velocity`](crate::Velocity) 与各驱动轮线速度之间相互转换
+/// 正向（`wheels_from`）即运动学逆解，用于下发轮速指令
+/// 反向（`velocity_from_wheels`）即运动学正解，用于由编码器/电机读数推算底盘速度
+pub trait WheelKinematics {
+    /// 运动学逆解：由底盘速度解算各驱动轮线速度，单位 m/s
+    fn wheels_from(&self, v: &Velocity) -> Vec<f32>;
+
+    /// 运动学正解：由各驱动轮线速度（单位 m/s）推算底盘速度
+    fn velocity_from_wheels(&self, wheels: &[f32]) -> Velocity;
+}
+
+/// 两轮差动底盘
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DiffDrive {
+    /// 两驱动轮连线中点到驱动轮的距离，单位 m
+    pub body_radius: f32,
+}
+
+impl WheelKinematics for DiffDrive {
+    /// `[left, right]`
+    fn wheels_from(&self, v: &Velocity) -> Vec<f32> {
+        let Velocity { vx, w, .. } = *v;
+        vec![vx - w * self.body_radius, vx + w * self.body_radius]
+    }
+
+    fn velocity_from_wheels(&self, wheels: &[f32]) -> Velocity {
+        let (left, right) = (wheels[0], wheels[1]);
+        Velocity {
+            vx: (left + right) / 2.0,
+            vy: 0.0,
+            w: (right - left) / (2.0 * self.body_radius),
+        }
+    }
+}
+
+/// 三轮全向底盘，三轮呈 120° 均布，`a` 轮朝向机器人正前方
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TriOmni {
+    /// 底盘中心到驱动轮的距离，单位 m
+    pub robot_radius: f32,
+}
+
+impl WheelKinematics for TriOmni {
+    /// `[a, b, c]`
+    fn wheels_from(&self, v: &Velocity) -> Vec<f32> {
+        const COS_30: f32 = 0.866_025_4;
+        const SIN_30: f32 = 0.5;
+
+        let Velocity { vx, vy, w } = *v;
+        let r_w = self.robot_radius * w;
+        vec![
+            vy + r_w,
+            -vx * COS_30 - vy * SIN_30 + r_w,
+            vx * COS_30 - vy * SIN_30 + r_w,
+        ]
+    }
+
+    fn velocity_from_wheels(&self, wheels: &[f32]) -> Velocity {
+        const SQRT_3: f32 = 1.732_050_8;
+
+        let (a, b, c) = (wheels[0], wheels[1], wheels[2]);
+        let w = (a + b + c) / (3.0 * self.robot_radius);
+        Velocity {
+            vx: (c - b) / SQRT_3,
+            vy: a - self.robot_radius * w,
+            w,
+        }
+    }
+}
+
+#[test]
+fn diff_drive_round_trip() {
+    let model = DiffDrive { body_radius: 0.3 };
+    let v = Velocity {
+        vx: 0.5,
+        vy: 0.0,
+        w: 0.2,
+    };
+    let wheels = model.wheels_from(&v);
+    let back = model.velocity_from_wheels(&wheels);
+    assert!((back.vx - v.vx).abs() < f32::EPSILON);
+    assert!((back.w - v.w).abs() < f32::EPSILON);
+}
+
+#[test]
+fn tri_omni_round_trip() {
+    let model = TriOmni { robot_radius: 0.25 };
+    let v = Velocity {
+        vx: 0.3,
+        vy: -0.4,
+        w: 0.5,
+    };
+    let wheels = model.wheels_from(&v);
+    let back = model.velocity_from_wheels(&wheels);
+    assert!((back.vx - v.vx).abs() < 1e-5);
+    assert!((back.vy - v.vy).abs() < 1e-5);
+    assert!((back.w - v.w).abs() < 1e-5);
+}